@@ -0,0 +1,149 @@
+use super::*;
+
+/// A `CardSet` is a compact representation of zero or more cards packed into
+/// a single `u64`, one bit per suit/value combination (52 bits used). Unlike
+/// `Hand`'s `Vec<Card>`, membership tests and set algebra (union,
+/// intersection, difference, subset) are constant-time bitwise operations
+/// instead of linear scans over the card list.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug, Default)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    /// Makes a new empty `CardSet`
+    pub fn new() -> CardSet {
+        CardSet(0)
+    }
+
+    fn bit_index(card: Card) -> u32 {
+        card.suit.ordinal() as u32 * 13 + card.value.ordinal() as u32
+    }
+
+    /// Adds a `Card` to the set
+    pub fn insert(&mut self, card: Card) {
+        self.0 |= 1u64 << CardSet::bit_index(card);
+    }
+
+    /// Removes a `Card` from the set
+    pub fn remove(&mut self, card: Card) {
+        self.0 &= !(1u64 << CardSet::bit_index(card));
+    }
+
+    /// Returns whether the set contains the given `Card`
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & (1u64 << CardSet::bit_index(card)) != 0
+    }
+
+    /// Returns the number of cards in the set
+    pub fn len(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Returns whether the set holds no cards
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the union of `self` and `other`
+    pub fn union(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    /// Returns the intersection of `self` and `other`
+    pub fn intersection(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    /// Returns the cards in `self` that are not in `other`
+    pub fn difference(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    /// Returns whether every card in `self` is also in `other`
+    pub fn is_subset(&self, other: CardSet) -> bool {
+        self.0 & other.0 == self.0
+    }
+
+    /// Converts the set back into a `Hand`, in ascending bit order
+    pub fn to_hand(&self) -> Hand {
+        let mut hand = Hand::new();
+        let mut bits = self.0;
+        while bits != 0 {
+            let index = bits.trailing_zeros();
+            let suit = *Suit::iterator().nth((index / 13) as usize).unwrap();
+            let value = *Value::iterator().nth((index % 13) as usize).unwrap();
+            hand.push_card(Card { suit: suit, value: value });
+            bits &= bits - 1;
+        }
+        hand
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_hand() -> Hand {
+        use Suit::*;
+        use Value::*;
+        Hand::from_cards(&[
+            Card { suit: Spades, value: Ace },
+            Card { suit: Hearts, value: Ten },
+            Card { suit: Diamonds, value: Two },
+            Card { suit: Clubs, value: King },
+        ])
+    }
+
+    #[test]
+    fn empty_set_is_empty() {
+        let set = CardSet::new();
+        assert!(set.is_empty());
+        assert_eq!(set.len(), 0);
+    }
+
+    #[test]
+    fn insert_remove_contains() {
+        let card = Card { suit: Suit::Spades, value: Value::Ace };
+        let mut set = CardSet::new();
+        assert!(!set.contains(card));
+        set.insert(card);
+        assert!(set.contains(card));
+        assert!(!set.is_empty());
+        assert_eq!(set.len(), 1);
+        set.remove(card);
+        assert!(!set.contains(card));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn union_intersection_difference_is_subset() {
+        let a = Card { suit: Suit::Spades, value: Value::Ace };
+        let b = Card { suit: Suit::Hearts, value: Value::King };
+        let mut set_a = CardSet::new();
+        set_a.insert(a);
+        let mut set_b = CardSet::new();
+        set_b.insert(b);
+
+        let union = set_a.union(set_b);
+        assert_eq!(union.len(), 2);
+        assert!(union.contains(a) && union.contains(b));
+
+        assert!(set_a.intersection(set_b).is_empty());
+        assert!(set_a.is_subset(union));
+        assert!(!union.is_subset(set_a));
+
+        let mut both = set_a;
+        both.insert(b);
+        assert_eq!(both.intersection(set_b), set_b);
+        assert_eq!(both.difference(set_b), set_a);
+    }
+
+    #[test]
+    fn hand_to_set_to_hand_round_trips() {
+        let hand = sample_hand();
+        let round_tripped = hand.to_set().to_hand();
+        assert_eq!(round_tripped.len(), hand.len());
+        for card in hand.cards() {
+            assert!(round_tripped.cards().contains(card));
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use rand::{thread_rng, Rng};
+
+use super::*;
+
+/// A `Cards` is anything that holds an ordered, mutable sequence of
+/// `Card`s (`Hand`, `Deck`, ...). Implementors need only provide `cards`
+/// and `mut_cards`; shuffling comes for free.
+pub trait Cards {
+    /// Returns the cards as a slice
+    fn cards(&self) -> &[Card];
+
+    /// Returns the cards as a mutable slice
+    fn mut_cards(&mut self) -> &mut [Card];
+
+    /// Shuffles the cards in place, drawing randomness from the
+    /// thread-local RNG
+    fn shuffle(&mut self) {
+        self.shuffle_with(&mut thread_rng());
+    }
+
+    /// Shuffles the cards in place via Fisher-Yates, drawing randomness
+    /// from the given RNG. Accepting the RNG lets callers pass a seeded
+    /// one so a shuffle (and everything downstream of it) can be replayed
+    /// deterministically in tests.
+    fn shuffle_with<R: Rng>(&mut self, rng: &mut R) {
+        let cards = self.mut_cards();
+        let num_cards = cards.len();
+        for i in (1..num_cards).rev() {
+            let j = rng.gen_range(0, i + 1);
+            cards.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Describes why a string could not be parsed into a `Value`, `Suit`, or
+/// `Card`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseCardError {
+    /// The rank portion of the string did not match any `Value`
+    InvalidValue(String),
+    /// The suit portion of the string did not match any `Suit`
+    InvalidSuit(String),
+    /// The string was not a recognizable abbreviated card
+    InvalidCard(String),
+}
+
+impl Display for ParseCardError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            ParseCardError::InvalidValue(ref s) => write!(f, "'{}' is not a valid card value", s),
+            ParseCardError::InvalidSuit(ref s) => write!(f, "'{}' is not a valid card suit", s),
+            ParseCardError::InvalidCard(ref s) => write!(f, "'{}' is not a valid card", s),
+        }
+    }
+}
+
+impl Error for ParseCardError {
+    fn description(&self) -> &str {
+        match *self {
+            ParseCardError::InvalidValue(_) => "invalid card value",
+            ParseCardError::InvalidSuit(_) => "invalid card suit",
+            ParseCardError::InvalidCard(_) => "invalid card",
+        }
+    }
+}
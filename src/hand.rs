@@ -1,5 +1,11 @@
 use std::fmt::{Display, Formatter, Result};
 use std::ops::AddAssign;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+#[cfg(feature = "serde")]
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
 
 use super::*;
 
@@ -12,7 +18,14 @@ pub struct Hand {
 }
 
 impl Display for Hand {
+    /// Writes the comma-separated abbreviations used by `from_strings` and
+    /// `FromStr`, e.g. "AS,10H". The alternate form (`{:#}`) writes the
+    /// space-separated Unicode suit symbols from `to_symbols` instead, e.g.
+    /// "A♠ 10♥".
     fn fmt(&self, f: &mut Formatter) -> Result {
+        if f.alternate() {
+            return write!(f, "{}", self.to_symbols());
+        }
         let mut result = String::new();
         for (i, card) in self.cards.iter().enumerate() {
             result.push_str(&card.to_str());
@@ -24,6 +37,33 @@ impl Display for Hand {
     }
 }
 
+fn value_abbreviation(value: Value) -> &'static str {
+    match value {
+        Value::Two => "2",
+        Value::Three => "3",
+        Value::Four => "4",
+        Value::Five => "5",
+        Value::Six => "6",
+        Value::Seven => "7",
+        Value::Eight => "8",
+        Value::Nine => "9",
+        Value::Ten => "10",
+        Value::Jack => "J",
+        Value::Queen => "Q",
+        Value::King => "K",
+        Value::Ace => "A"
+    }
+}
+
+fn suit_symbol(suit: Suit) -> char {
+    match suit {
+        Suit::Spades => '♠',
+        Suit::Hearts => '♥',
+        Suit::Diamonds => '♦',
+        Suit::Clubs => '♣'
+    }
+}
+
 impl Clone for Hand {
 	fn clone(&self) -> Hand {
 		return Hand {
@@ -134,4 +174,117 @@ impl Hand {
     pub fn cards_of_suit(&self, suit: Suit) -> Vec<Card> {
         cards_of_suit(&self.cards, suit)
     }
+
+    /// Converts the `Hand` into a `CardSet`
+    pub fn to_set(&self) -> CardSet {
+        let mut set = CardSet::new();
+        for card in &self.cards {
+            set.insert(*card);
+        }
+        set
+    }
+
+    /// Renders the hand using Unicode suit glyphs, e.g. "A♠ 10♥", for
+    /// terminal/TUI display where the ASCII suit-letter abbreviations are
+    /// hard to read at a glance
+    pub fn to_symbols(&self) -> String {
+        let mut result = String::new();
+        for (i, card) in self.cards.iter().enumerate() {
+            if i > 0 {
+                result.push(' ');
+            }
+            result.push_str(value_abbreviation(card.value));
+            result.push(suit_symbol(card.suit));
+        }
+        result
+    }
+}
+
+/// Parses a single abbreviated card, e.g. "AS" or "10H". Shared by `Hand`'s
+/// `FromStr` and `Deserialize` impls so both report malformed input as an
+/// `Err` rather than panicking like the `card!` macro.
+fn parse_card(s: &str) -> ::std::result::Result<Card, ParseCardError> {
+    let suit_start = match s.char_indices().last() {
+        Some((i, _)) if i > 0 => i,
+        _ => return Err(ParseCardError::InvalidCard(s.to_string())),
+    };
+    let (value_str, suit_str) = s.split_at(suit_start);
+    let value = Value::from_str(value_str)?;
+    let suit = match suit_str {
+        "S" => Suit::Spades,
+        "H" => Suit::Hearts,
+        "D" => Suit::Diamonds,
+        "C" => Suit::Clubs,
+        _ => return Err(ParseCardError::InvalidSuit(suit_str.to_string()))
+    };
+    Ok(Card { suit: suit, value: value })
+}
+
+impl FromStr for Hand {
+    type Err = ParseCardError;
+
+    /// Parses a comma-separated list of abbreviated cards, as produced by
+    /// `Display`, so `hand.to_string().parse::<Hand>()` round-trips. Unlike
+    /// `from_strings` (which uses the panicking `card!` macro), malformed
+    /// input is reported as an `Err` rather than a panic.
+    fn from_str(s: &str) -> ::std::result::Result<Hand, ParseCardError> {
+        let mut hand = Hand::new();
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Ok(hand);
+        }
+        for token in trimmed.split(',') {
+            hand.push_card(parse_card(token.trim())?);
+        }
+        Ok(hand)
+    }
+}
+
+/// `Hand` serializes as a list of abbreviated card strings rather than as
+/// its internal struct layout, so it round-trips through the same `card!`
+/// abbreviations used by `Display` and `from_strings`.
+#[cfg(feature = "serde")]
+impl Serialize for Hand {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.cards.len()))?;
+        for card in &self.cards {
+            seq.serialize_element(&card.to_str())?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Hand {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Hand, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct HandVisitor;
+
+        impl<'de> Visitor<'de> for HandVisitor {
+            type Value = Hand;
+
+            fn expecting(&self, formatter: &mut Formatter) -> Result {
+                formatter.write_str("a sequence of abbreviated card strings")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> ::std::result::Result<Hand, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut hand = Hand::new();
+                while let Some(s) = seq.next_element::<String>()? {
+                    let card = parse_card(&s).map_err(::serde::de::Error::custom)?;
+                    hand.push_card(card);
+                }
+                Ok(hand)
+            }
+        }
+
+        deserializer.deserialize_seq(HandVisitor)
+    }
 }
@@ -0,0 +1,329 @@
+use super::*;
+
+/// The classification of the best 5-card poker hand found within a `Hand`.
+/// Variants are declared weakest to strongest, and each carries its
+/// tiebreaker ranks (most significant first: the grouped ranks, then the
+/// kickers from highest to lowest) so the derived `Ord` compares variant
+/// first and kickers second, deciding winners directly.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum HandRank {
+    HighCard([u8; 5]),
+    Pair([u8; 4]),
+    TwoPair([u8; 3]),
+    ThreeOfAKind([u8; 3]),
+    Straight(u8),
+    Flush([u8; 5]),
+    FullHouse([u8; 2]),
+    FourOfAKind([u8; 2]),
+    StraightFlush(u8)
+}
+
+/// Poker rank of a `Value`, with `Ace` high (14) rather than the `ordinal`
+/// used for `CardSet` bit positions.
+fn poker_rank(value: Value) -> u8 {
+    match value {
+        Value::Two => 2,
+        Value::Three => 3,
+        Value::Four => 4,
+        Value::Five => 5,
+        Value::Six => 6,
+        Value::Seven => 7,
+        Value::Eight => 8,
+        Value::Nine => 9,
+        Value::Ten => 10,
+        Value::Jack => 11,
+        Value::Queen => 12,
+        Value::King => 13,
+        Value::Ace => 14
+    }
+}
+
+/// Every 5-card subset of `cards`, in combination order
+fn combinations_5(cards: &[Card]) -> Vec<Vec<Card>> {
+    let n = cards.len();
+    let mut result = Vec::new();
+    if n < 5 {
+        return result;
+    }
+    let mut indices = [0usize, 1, 2, 3, 4];
+    loop {
+        result.push(indices.iter().map(|&i| cards[i]).collect());
+
+        let mut i = 4;
+        while indices[i] == i + n - 5 {
+            if i == 0 {
+                return result;
+            }
+            i -= 1;
+        }
+        indices[i] += 1;
+        for j in (i + 1)..5 {
+            indices[j] = indices[j - 1] + 1;
+        }
+    }
+}
+
+/// Classifies exactly five cards
+fn evaluate_five(cards: &[Card]) -> HandRank {
+    let mut ranks: Vec<u8> = cards.iter().map(|c| poker_rank(c.value)).collect();
+    ranks.sort();
+    ranks.reverse();
+
+    let flush = cards.iter().all(|c| c.suit == cards[0].suit);
+
+    let mut distinct = ranks.clone();
+    distinct.dedup();
+    let straight_high = if distinct.len() == 5 && distinct[0] - distinct[4] == 4 {
+        Some(distinct[0])
+    } else if distinct.as_slice() == [14u8, 5, 4, 3, 2] {
+        Some(5)
+    } else {
+        None
+    };
+
+    // (count, rank) per distinct rank, sorted highest count then highest rank first
+    let mut groups: Vec<(u8, u8)> = distinct
+        .iter()
+        .map(|&r| (ranks.iter().filter(|&&x| x == r).count() as u8, r))
+        .collect();
+    groups.sort();
+    groups.reverse();
+    let counts: Vec<u8> = groups.iter().map(|g| g.0).collect();
+    let group_ranks: Vec<u8> = groups.iter().map(|g| g.1).collect();
+
+    let mut kickers = [0u8; 5];
+    kickers.copy_from_slice(&ranks);
+
+    if let Some(high) = straight_high {
+        if flush {
+            return HandRank::StraightFlush(high);
+        }
+    }
+    if counts.as_slice() == [4u8, 1] {
+        HandRank::FourOfAKind([group_ranks[0], group_ranks[1]])
+    } else if counts.as_slice() == [3u8, 2] {
+        HandRank::FullHouse([group_ranks[0], group_ranks[1]])
+    } else if flush {
+        HandRank::Flush(kickers)
+    } else if let Some(high) = straight_high {
+        HandRank::Straight(high)
+    } else if counts.as_slice() == [3u8, 1, 1] {
+        HandRank::ThreeOfAKind([group_ranks[0], group_ranks[1], group_ranks[2]])
+    } else if counts.as_slice() == [2u8, 2, 1] {
+        HandRank::TwoPair([group_ranks[0], group_ranks[1], group_ranks[2]])
+    } else if counts.as_slice() == [2u8, 1, 1, 1] {
+        HandRank::Pair([group_ranks[0], group_ranks[1], group_ranks[2], group_ranks[3]])
+    } else {
+        HandRank::HighCard(kickers)
+    }
+}
+
+impl Hand {
+    /// Classifies the best 5-card poker hand contained within this `Hand`.
+    /// For hands larger than five cards (e.g. seven-card hold'em), every
+    /// 5-card subset is evaluated and the highest-ranked one is returned.
+    /// Returns `None` if the hand holds fewer than five cards, since no
+    /// 5-card combination exists to classify.
+    pub fn evaluate(&self) -> Option<HandRank> {
+        combinations_5(&self.cards)
+            .iter()
+            .map(|subset| evaluate_five(subset))
+            .max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(cards: &[(Value, Suit)]) -> Hand {
+        Hand::from_cards(
+            &cards
+                .iter()
+                .map(|&(value, suit)| Card { suit: suit, value: value })
+                .collect::<Vec<Card>>(),
+        )
+    }
+
+    #[test]
+    fn too_few_cards_is_none() {
+        use Suit::*;
+        use Value::*;
+        let h = hand(&[(Ace, Spades), (King, Spades)]);
+        assert_eq!(h.evaluate(), None);
+    }
+
+    #[test]
+    fn high_card() {
+        use Suit::*;
+        use Value::*;
+        let h = hand(&[
+            (Two, Spades),
+            (Five, Hearts),
+            (Nine, Diamonds),
+            (Jack, Clubs),
+            (King, Spades),
+        ]);
+        assert_eq!(h.evaluate(), Some(HandRank::HighCard([13, 11, 9, 5, 2])));
+    }
+
+    #[test]
+    fn pair() {
+        use Suit::*;
+        use Value::*;
+        let h = hand(&[
+            (Two, Spades),
+            (Two, Hearts),
+            (Nine, Diamonds),
+            (Jack, Clubs),
+            (King, Spades),
+        ]);
+        assert_eq!(h.evaluate(), Some(HandRank::Pair([2, 13, 11, 9])));
+    }
+
+    #[test]
+    fn two_pair() {
+        use Suit::*;
+        use Value::*;
+        let h = hand(&[
+            (Two, Spades),
+            (Two, Hearts),
+            (Nine, Diamonds),
+            (Nine, Clubs),
+            (King, Spades),
+        ]);
+        assert_eq!(h.evaluate(), Some(HandRank::TwoPair([9, 2, 13])));
+    }
+
+    #[test]
+    fn three_of_a_kind() {
+        use Suit::*;
+        use Value::*;
+        let h = hand(&[
+            (Two, Spades),
+            (Two, Hearts),
+            (Two, Diamonds),
+            (Nine, Clubs),
+            (King, Spades),
+        ]);
+        assert_eq!(h.evaluate(), Some(HandRank::ThreeOfAKind([2, 13, 9])));
+    }
+
+    #[test]
+    fn straight() {
+        use Suit::*;
+        use Value::*;
+        let h = hand(&[
+            (Four, Spades),
+            (Five, Hearts),
+            (Six, Diamonds),
+            (Seven, Clubs),
+            (Eight, Spades),
+        ]);
+        assert_eq!(h.evaluate(), Some(HandRank::Straight(8)));
+    }
+
+    #[test]
+    fn ace_low_wheel_straight() {
+        use Suit::*;
+        use Value::*;
+        let h = hand(&[
+            (Ace, Spades),
+            (Two, Hearts),
+            (Three, Diamonds),
+            (Four, Clubs),
+            (Five, Spades),
+        ]);
+        assert_eq!(h.evaluate(), Some(HandRank::Straight(5)));
+    }
+
+    #[test]
+    fn flush() {
+        use Suit::*;
+        use Value::*;
+        let h = hand(&[
+            (Two, Spades),
+            (Five, Spades),
+            (Nine, Spades),
+            (Jack, Spades),
+            (King, Spades),
+        ]);
+        assert_eq!(h.evaluate(), Some(HandRank::Flush([13, 11, 9, 5, 2])));
+    }
+
+    #[test]
+    fn full_house() {
+        use Suit::*;
+        use Value::*;
+        let h = hand(&[
+            (Two, Spades),
+            (Two, Hearts),
+            (Two, Diamonds),
+            (King, Clubs),
+            (King, Spades),
+        ]);
+        assert_eq!(h.evaluate(), Some(HandRank::FullHouse([2, 13])));
+    }
+
+    #[test]
+    fn four_of_a_kind() {
+        use Suit::*;
+        use Value::*;
+        let h = hand(&[
+            (Two, Spades),
+            (Two, Hearts),
+            (Two, Diamonds),
+            (Two, Clubs),
+            (King, Spades),
+        ]);
+        assert_eq!(h.evaluate(), Some(HandRank::FourOfAKind([2, 13])));
+    }
+
+    #[test]
+    fn straight_flush_beats_flush_and_straight() {
+        use Suit::*;
+        use Value::*;
+        let straight_flush = hand(&[
+            (Four, Spades),
+            (Five, Spades),
+            (Six, Spades),
+            (Seven, Spades),
+            (Eight, Spades),
+        ]);
+        let flush = hand(&[
+            (Two, Hearts),
+            (Five, Hearts),
+            (Nine, Hearts),
+            (Jack, Hearts),
+            (King, Hearts),
+        ]);
+        let straight = hand(&[
+            (Four, Clubs),
+            (Five, Diamonds),
+            (Six, Hearts),
+            (Seven, Spades),
+            (Eight, Clubs),
+        ]);
+        assert!(straight_flush.evaluate() > flush.evaluate());
+        assert!(straight_flush.evaluate() > straight.evaluate());
+        assert_eq!(straight_flush.evaluate(), Some(HandRank::StraightFlush(8)));
+    }
+
+    #[test]
+    fn seven_card_hand_picks_best_five() {
+        use Suit::*;
+        use Value::*;
+        // Two pair (Aces, Kings) plus three dead cards; best five should not
+        // be the weaker high-card combination some subsets would form.
+        let h = hand(&[
+            (Ace, Spades),
+            (Ace, Hearts),
+            (King, Spades),
+            (King, Hearts),
+            (Two, Clubs),
+            (Five, Diamonds),
+            (Nine, Clubs),
+        ]);
+        assert_eq!(h.evaluate(), Some(HandRank::TwoPair([14, 13, 9])));
+    }
+}
@@ -1,9 +1,12 @@
 use std::slice::Iter;
+use std::str::FromStr;
 
 use self::Value::*;
+use super::error::ParseCardError;
 
 // Standard card values
 #[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Value {
     Two,
     Three,
@@ -66,4 +69,29 @@ impl Value {
         }
         value_str
     }
+}
+
+impl FromStr for Value {
+    type Err = ParseCardError;
+
+    /// Parses either a full name ("Ace") or the single-character / "10"
+    /// abbreviation used by `card!` and `Hand`'s `Display` output.
+    fn from_str(s: &str) -> Result<Value, ParseCardError> {
+        match s {
+            "Ace" | "A" => Ok(Ace),
+            "Two" | "2" => Ok(Two),
+            "Three" | "3" => Ok(Three),
+            "Four" | "4" => Ok(Four),
+            "Five" | "5" => Ok(Five),
+            "Six" | "6" => Ok(Six),
+            "Seven" | "7" => Ok(Seven),
+            "Eight" | "8" => Ok(Eight),
+            "Nine" | "9" => Ok(Nine),
+            "Ten" | "10" => Ok(Ten),
+            "Jack" | "J" => Ok(Jack),
+            "Queen" | "Q" => Ok(Queen),
+            "King" | "K" => Ok(King),
+            _ => Err(ParseCardError::InvalidValue(s.to_string()))
+        }
+    }
 }
\ No newline at end of file